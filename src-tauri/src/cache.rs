@@ -0,0 +1,93 @@
+//! Persistent on-disk cache for translation lookups, keyed by provider,
+//! language pair and a normalized hash of the source text. Backs the
+//! `readnlearn-cache://translate/...` protocol so repeated lookups (and offline
+//! re-reading) don't re-hit the network or burn paid API quota.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection};
+use sha2::{Digest, Sha256};
+
+/// `rusqlite::Connection` is `Send` but not `Sync` — it isn't safe to share
+/// across threads without serializing access — and Tauri's managed state
+/// must be `Sync` since commands and protocol handlers run concurrently.
+/// The `Mutex` here serializes access the same way `VaultState` does for
+/// the credential vault.
+pub struct TranslationCache {
+    conn: Mutex<Connection>,
+}
+
+impl TranslationCache {
+    pub fn open(db_path: &Path) -> Result<Self, String> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let conn = Connection::open(db_path).map_err(|e| e.to_string())?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS translations (
+                provider TEXT NOT NULL,
+                source_lang TEXT NOT NULL,
+                target_lang TEXT NOT NULL,
+                text_hash TEXT NOT NULL,
+                response TEXT NOT NULL,
+                PRIMARY KEY (provider, source_lang, target_lang, text_hash)
+            )",
+            [],
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    pub fn get(
+        &self,
+        provider: &str,
+        source_lang: &str,
+        target_lang: &str,
+        text: &str,
+    ) -> Result<Option<String>, String> {
+        let hash = hash_text(text);
+        let conn = self.conn.lock().map_err(|e| e.to_string())?;
+        conn.query_row(
+            "SELECT response FROM translations
+             WHERE provider = ?1 AND source_lang = ?2 AND target_lang = ?3 AND text_hash = ?4",
+            params![provider, source_lang, target_lang, hash],
+            |row| row.get(0),
+        )
+        .map(Some)
+        .or_else(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => Ok(None),
+            other => Err(other.to_string()),
+        })
+    }
+
+    pub fn put(
+        &self,
+        provider: &str,
+        source_lang: &str,
+        target_lang: &str,
+        text: &str,
+        response: &str,
+    ) -> Result<(), String> {
+        let hash = hash_text(text);
+        let conn = self.conn.lock().map_err(|e| e.to_string())?;
+        conn.execute(
+            "INSERT OR REPLACE INTO translations
+                (provider, source_lang, target_lang, text_hash, response)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![provider, source_lang, target_lang, hash, response],
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.trim().to_lowercase()
+}
+
+fn hash_text(text: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(normalize(text).as_bytes());
+    format!("{:x}", hasher.finalize())
+}
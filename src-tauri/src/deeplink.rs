@@ -0,0 +1,99 @@
+//! `readnlearn://` deep link handling.
+//!
+//! The actual OS-level registration (a `.desktop` entry with the
+//! `x-scheme-handler/readnlearn` MIME association on Linux, the matching
+//! `CFBundleURLTypes`/registry entries on macOS and Windows) is generated by
+//! `tauri-plugin-deep-link` from the `deep-link` config in
+//! `tauri.conf.json`; this module only parses incoming URLs and forwards
+//! them to the frontend as an event.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+pub const DEEP_LINK_EVENT: &str = "deep-link://action";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "action", rename_all = "kebab-case")]
+pub enum DeepLinkAction {
+    Import { url: String },
+    AddWord { text: String, lang: Option<String> },
+}
+
+/// Parses one incoming `readnlearn://` URL and emits the corresponding
+/// action to the frontend. Unknown hosts/paths are logged and ignored
+/// rather than treated as an error, since a stray or malformed link
+/// shouldn't be able to crash the handler.
+pub fn handle_url(app: &AppHandle, url: &str) {
+    match parse_action(url) {
+        Some(action) => {
+            let _ = app.emit(DEEP_LINK_EVENT, action);
+        }
+        None => {
+            eprintln!("Ignoring unrecognized deep link: {}", url);
+        }
+    }
+}
+
+fn parse_action(url: &str) -> Option<DeepLinkAction> {
+    let parsed = url::Url::parse(url).ok()?;
+    if parsed.scheme() != "readnlearn" {
+        return None;
+    }
+
+    let query: std::collections::HashMap<String, String> = parsed
+        .query_pairs()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+
+    match parsed.host_str()? {
+        "import" => Some(DeepLinkAction::Import {
+            url: query.get("url")?.clone(),
+        }),
+        "add-word" => Some(DeepLinkAction::AddWord {
+            text: query.get("text")?.clone(),
+            lang: query.get("lang").cloned(),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_import_action() {
+        let action = parse_action("readnlearn://import?url=https://example.com/article").unwrap();
+        assert!(matches!(action, DeepLinkAction::Import { url } if url == "https://example.com/article"));
+    }
+
+    #[test]
+    fn parses_add_word_action_with_lang() {
+        let action = parse_action("readnlearn://add-word?text=bonjour&lang=fr").unwrap();
+        assert!(matches!(
+            action,
+            DeepLinkAction::AddWord { text, lang } if text == "bonjour" && lang.as_deref() == Some("fr")
+        ));
+    }
+
+    #[test]
+    fn add_word_lang_is_optional() {
+        let action = parse_action("readnlearn://add-word?text=bonjour").unwrap();
+        assert!(matches!(action, DeepLinkAction::AddWord { lang: None, .. }));
+    }
+
+    #[test]
+    fn rejects_unknown_scheme() {
+        assert!(parse_action("other://import?url=https://example.com").is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_action() {
+        assert!(parse_action("readnlearn://unknown-action").is_none());
+    }
+
+    #[test]
+    fn rejects_missing_required_param() {
+        assert!(parse_action("readnlearn://add-word").is_none());
+    }
+}
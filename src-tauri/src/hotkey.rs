@@ -0,0 +1,151 @@
+//! Global shortcut + system tray for a system-wide "quick lookup" popup.
+//!
+//! Lets a learner select text in any application, press a configurable
+//! hotkey, and get an instant translation/definition popup without
+//! switching to the main reader window.
+
+use arboard::Clipboard;
+use device_query::{DeviceQuery, DeviceState};
+use serde::{Deserialize, Serialize};
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, PhysicalPosition, WebviewUrl, WebviewWindowBuilder};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+const POPUP_LABEL: &str = "quick-lookup";
+const DEFAULT_KEYS: &str = "CmdOrCtrl+Shift+L";
+/// Target language used until the popup has its own language picker; the
+/// learner's configured deck language should replace this (tracked as
+/// follow-up work, see `show_quick_lookup`).
+const DEFAULT_TARGET_LANG: &str = "en";
+
+/// User-editable binding. `enabled` lets the setting be toggled
+/// off without losing the configured key combo, and registration is
+/// tolerant of being re-run (e.g. after the user changes `keys`) by
+/// unregistering the previous shortcut first. This module does not store
+/// the binding: startup registers `QuickLookupBinding::default()`, and a
+/// binding set through `set_quick_lookup_binding` lasts until the app exits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuickLookupBinding {
+    pub keys: String,
+    pub enabled: bool,
+}
+
+impl Default for QuickLookupBinding {
+    fn default() -> Self {
+        Self {
+            keys: DEFAULT_KEYS.to_string(),
+            enabled: true,
+        }
+    }
+}
+
+pub fn setup(app: &AppHandle, binding: &QuickLookupBinding) -> tauri::Result<()> {
+    setup_tray(app)?;
+    apply_binding(app, binding)
+}
+
+/// Lets the settings UI change the binding at runtime without a restart.
+#[tauri::command]
+pub fn set_quick_lookup_binding(app: AppHandle, binding: QuickLookupBinding) -> Result<(), String> {
+    apply_binding(&app, &binding).map_err(|e| e.to_string())
+}
+
+/// Re-registers the global shortcut for a (possibly just-changed) binding,
+/// unregistering whatever was previously bound first.
+pub fn apply_binding(app: &AppHandle, binding: &QuickLookupBinding) -> tauri::Result<()> {
+    let shortcuts = app.global_shortcut();
+    shortcuts.unregister_all()?;
+
+    if !binding.enabled {
+        return Ok(());
+    }
+
+    let shortcut: Shortcut = binding
+        .keys
+        .parse()
+        .map_err(|e| tauri::Error::Anyhow(anyhow::anyhow!("Invalid shortcut {:?}: {e}", binding.keys)))?;
+
+    let app_handle = app.clone();
+    shortcuts.on_shortcut(shortcut, move |_app, _shortcut, event| {
+        if event.state() == ShortcutState::Pressed {
+            show_quick_lookup(&app_handle);
+        }
+    })
+}
+
+fn setup_tray(app: &AppHandle) -> tauri::Result<()> {
+    let quick_lookup = MenuItem::with_id(app, "quick-lookup", "Quick Lookup", true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(app, &[&quick_lookup, &quit])?;
+
+    TrayIconBuilder::new()
+        .menu(&menu)
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "quick-lookup" => show_quick_lookup(app),
+            "quit" => app.exit(0),
+            _ => {}
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Reads the OS clipboard and shows a small always-on-top popup near the
+/// cursor, pointed at the selected text. The popup's own webview runs the
+/// lookup through the `readnlearn-cache://` protocol (the same cached
+/// translation pipeline chunk0-3 wired up for the main reader), so this
+/// function only needs to gather the text and position, not talk to any
+/// provider itself.
+///
+/// Known follow-up gaps, left for a later request rather than silently
+/// assumed done: this reads the regular clipboard only, not X11's primary
+/// selection, so a learner has to explicitly copy (not just highlight) the
+/// text first; and the target language is hardcoded to
+/// [`DEFAULT_TARGET_LANG`] until the popup gets its own language picker
+/// wired to the learner's deck settings.
+fn show_quick_lookup(app: &AppHandle) {
+    let text = match Clipboard::new().and_then(|mut c| c.get_text()) {
+        Ok(text) if !text.trim().is_empty() => text,
+        _ => {
+            eprintln!("Quick lookup triggered but the clipboard has no text");
+            return;
+        }
+    };
+
+    // device_query reports physical device pixels, not logical ones; handing
+    // those to a logical-position API would double-apply the monitor's scale
+    // factor and place the popup away from the cursor on any scaled display.
+    let mouse = DeviceState::new().get_mouse();
+    let position = PhysicalPosition::new(mouse.coords.0 as f64, mouse.coords.1 as f64);
+
+    let url = format!(
+        "quick-lookup.html?text={}&lang={}",
+        urlencoding::encode(&text),
+        DEFAULT_TARGET_LANG
+    );
+
+    if let Some(window) = app.get_webview_window(POPUP_LABEL) {
+        let _ = window.set_position(position);
+        let _ = window.eval(&format!("window.location.replace({:?})", url));
+        let _ = window.show();
+        let _ = window.set_focus();
+        return;
+    }
+
+    let popup = WebviewWindowBuilder::new(app, POPUP_LABEL, WebviewUrl::App(url.into()))
+        .title("Quick Lookup")
+        .inner_size(360.0, 220.0)
+        .always_on_top(true)
+        .decorations(false)
+        .skip_taskbar(true)
+        .visible(false)
+        .build();
+
+    if let Ok(popup) = popup {
+        let _ = popup.set_position(position);
+        let _ = popup.show();
+        let _ = popup.set_focus();
+    }
+}
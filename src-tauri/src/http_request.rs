@@ -0,0 +1,151 @@
+//! Generic HTTP command that replaces the old per-provider proxies
+//! (`openai_proxy`, `deepl_proxy`, `google_proxy`). Callers describe the
+//! request and auth scheme once, so wiring up a new translation/LLM
+//! provider no longer requires shipping new Rust.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+/// How the response body should be handed back across the IPC bridge.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResponseType {
+    Json,
+    Text,
+    /// Base64-encoded so binary payloads (audio, dictionary blobs) survive
+    /// the trip through the webview bridge.
+    Binary,
+}
+
+/// Auth scheme applied on top of the caller-supplied headers/url.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Auth {
+    Bearer { token: String },
+    DeeplAuthKey { key: String },
+    QueryKey { name: String, value: String },
+    None,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRequestOptions {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<String>,
+    #[serde(default = "default_response_type")]
+    pub response_type: ResponseType,
+    #[serde(default)]
+    pub auth: Auth,
+    #[serde(default = "default_follow_redirects")]
+    pub follow_redirects: bool,
+    #[serde(default = "default_max_redirections")]
+    pub max_redirections: usize,
+    pub connect_timeout_ms: Option<u64>,
+    pub read_timeout_ms: Option<u64>,
+    pub timeout_ms: Option<u64>,
+}
+
+fn default_response_type() -> ResponseType {
+    ResponseType::Json
+}
+
+fn default_follow_redirects() -> bool {
+    true
+}
+
+fn default_max_redirections() -> usize {
+    10
+}
+
+impl Default for Auth {
+    fn default() -> Self {
+        Auth::None
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+#[tauri::command]
+pub async fn http_request(options: HttpRequestOptions) -> Result<HttpResponse, String> {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(ms) = options.connect_timeout_ms {
+        builder = builder.connect_timeout(Duration::from_millis(ms));
+    }
+    let read_or_total = options.read_timeout_ms.or(options.timeout_ms);
+    if let Some(ms) = read_or_total {
+        builder = builder.timeout(Duration::from_millis(ms));
+    }
+    builder = if options.follow_redirects {
+        builder.redirect(reqwest::redirect::Policy::limited(options.max_redirections))
+    } else {
+        builder.redirect(reqwest::redirect::Policy::none())
+    };
+
+    let client = builder
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    // Query-key auth needs to land in the URL before the request builder is
+    // created, so resolve the final URL first.
+    let url = match &options.auth {
+        Auth::QueryKey { name, value } => {
+            let sep = if options.url.contains('?') { "&" } else { "?" };
+            format!("{}{}{}={}", options.url, sep, name, urlencoding::encode(value))
+        }
+        _ => options.url.clone(),
+    };
+
+    let mut req = match options.method.to_uppercase().as_str() {
+        "GET" => client.get(&url),
+        "POST" => client.post(&url),
+        "PUT" => client.put(&url),
+        "PATCH" => client.patch(&url),
+        "DELETE" => client.delete(&url),
+        other => return Err(format!("Unsupported method: {}", other)),
+    };
+
+    for (name, value) in &options.headers {
+        req = req.header(name, value);
+    }
+
+    req = match &options.auth {
+        Auth::Bearer { token } => req.bearer_auth(token),
+        Auth::DeeplAuthKey { key } => req.header("Authorization", format!("DeepL-Auth-Key {}", key)),
+        Auth::QueryKey { .. } | Auth::None => req,
+    };
+
+    if let Some(body) = options.body {
+        req = req.header("Content-Type", "application/json").body(body);
+    }
+
+    let resp = req.send().await.map_err(|e| e.to_string())?;
+    let status = resp.status().as_u16();
+    let headers = resp
+        .headers()
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or_default().to_string()))
+        .collect();
+
+    let body = match options.response_type {
+        ResponseType::Binary => {
+            let bytes = resp.bytes().await.map_err(|e| e.to_string())?;
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        }
+        ResponseType::Json | ResponseType::Text => resp.text().await.map_err(|e| e.to_string())?,
+    };
+
+    Ok(HttpResponse { status, headers, body })
+}
@@ -1,123 +1,52 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use reqwest;
-
-// Generic provider proxies
-#[tauri::command]
-async fn openai_proxy(
-    api_key: String,
-    base_url: Option<String>,
-    method: String,
-    path: String,
-    body: Option<String>,
-) -> Result<String, String> {
-    if api_key.is_empty() { return Err("Missing OpenAI API key".into()); }
-    let base = base_url.unwrap_or_else(|| "https://api.openai.com".to_string());
-    let url = format!("{}{}", base, path);
-    
-    // Create client with timeout
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    
-    let mut req = match method.to_uppercase().as_str() {
-        "POST" => client.post(&url),
-        "GET" => client.get(&url),
-        "PUT" => client.put(&url),
-        "PATCH" => client.patch(&url),
-        "DELETE" => client.delete(&url),
-        _ => return Err("Unsupported method".into()),
-    }
-    .bearer_auth(api_key)
-    .header("Content-Type", "application/json");
-
-    if let Some(b) = body { req = req.body(b); }
-
-    let resp = req.send().await.map_err(|e| e.to_string())?;
-    let text = resp.text().await.map_err(|e| e.to_string())?;
-    Ok(text)
-}
-
-#[tauri::command]
-async fn deepl_proxy(
-    api_key: String,
-    base_url: Option<String>,
-    method: String,
-    path: String,
-    body: Option<String>,
-) -> Result<String, String> {
-    if api_key.is_empty() { return Err("Missing DeepL API key".into()); }
-    let base = base_url.unwrap_or_else(|| "https://api-free.deepl.com".to_string());
-    let url = format!("{}{}", base, path);
-    
-    // Create client with timeout
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    
-    let mut req = match method.to_uppercase().as_str() {
-        "POST" => client.post(&url),
-        "GET" => client.get(&url),
-        "PUT" => client.put(&url),
-        "PATCH" => client.patch(&url),
-        "DELETE" => client.delete(&url),
-        _ => return Err("Unsupported method".into()),
-    }
-    .header("Authorization", format!("DeepL-Auth-Key {}", api_key));
-
-    if let Some(b) = body { req = req.header("Content-Type", "application/json").body(b); }
-
-    let resp = req.send().await.map_err(|e| e.to_string())?;
-    let text = resp.text().await.map_err(|e| e.to_string())?;
-    Ok(text)
-}
-
-#[tauri::command]
-async fn google_proxy(
-    api_key: String,
-    base_url: Option<String>,
-    method: String,
-    path: String,
-    body: Option<String>,
-) -> Result<String, String> {
-    if api_key.is_empty() { return Err("Missing Google API key".into()); }
-    let base = base_url.unwrap_or_else(|| "https://translation.googleapis.com".to_string());
-    let sep = if path.contains('?') { "&" } else { "?" };
-    let url = format!("{}{}{}key={}", base, path, sep, api_key);
-    
-    // Create client with timeout
-    let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    
-    let mut req = match method.to_uppercase().as_str() {
-        "POST" => client.post(&url),
-        "GET" => client.get(&url),
-        "PUT" => client.put(&url),
-        "PATCH" => client.patch(&url),
-        "DELETE" => client.delete(&url),
-        _ => return Err("Unsupported method".into()),
-    };
-
-    if let Some(b) = body { req = req.header("Content-Type", "application/json").body(b); }
-
-    let resp = req.send().await.map_err(|e| e.to_string())?;
-    let text = resp.text().await.map_err(|e| e.to_string())?;
-    Ok(text)
-}
+mod cache;
+mod deeplink;
+mod hotkey;
+mod http_request;
+mod protocol;
+mod vault;
+
+use hotkey::{set_quick_lookup_binding, QuickLookupBinding};
+use http_request::http_request;
+use tauri::Manager;
+use tauri_plugin_deep_link::DeepLinkExt;
+use vault::{delete_credential, get_credential, lock_vault, store_credential, unlock_vault, VaultState};
 
 fn main() {
-    tauri::Builder::default()
+    let builder = tauri::Builder::default()
         .plugin(tauri_plugin_window_state::Builder::default().build())
+        .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .manage(VaultState::default())
+        .setup(|app| {
+            let cache_path = app.path().app_cache_dir()?.join("translations.sqlite");
+            let cache = cache::TranslationCache::open(&cache_path).map_err(std::io::Error::other)?;
+            app.manage(cache);
+
+            let handle = app.handle().clone();
+            app.deep_link().on_open_url(move |event| {
+                for url in event.urls() {
+                    deeplink::handle_url(&handle, url.as_str());
+                }
+            });
+
+            hotkey::setup(app.handle(), &QuickLookupBinding::default())?;
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
-            openai_proxy,
-            deepl_proxy,
-            google_proxy,
-        ])
+            http_request,
+            set_quick_lookup_binding,
+            unlock_vault,
+            lock_vault,
+            store_credential,
+            get_credential,
+            delete_credential
+        ]);
+
+    protocol::register(builder)
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
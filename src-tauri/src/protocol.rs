@@ -0,0 +1,256 @@
+//! `readnlearn-cache://translate/<provider>/<lang-pair>/<text>` custom protocol.
+//!
+//! The webview requests translations through this scheme instead of calling
+//! the `http_request` command directly, so lookups can be served from the
+//! on-disk [`TranslationCache`] without the UI needing to know whether a
+//! given phrase was already translated. The handler resolves the
+//! `UriSchemeResponder` asynchronously so a cache miss (which has to hit the
+//! network) never blocks the webview's message loop.
+//!
+//! Deliberately a distinct scheme from the OS-registered `readnlearn://`
+//! deep link (see `deeplink.rs`): that one is handed to the app by the
+//! operating system for importing articles/words from a browser, this one
+//! is resolved entirely in-process by the webview for cached lookups.
+//! Reusing the same scheme name for both would be ambiguous to maintain
+//! and risks the two handlers fighting over the same requests.
+
+use tauri::http::{Request, Response};
+use tauri::{Manager, UriSchemeResponder};
+
+use crate::cache::TranslationCache;
+use crate::http_request::{http_request, Auth, HttpRequestOptions, ResponseType};
+use crate::vault::VaultState;
+
+pub const SCHEME: &str = "readnlearn-cache";
+
+/// Where the source text goes: OpenAI has no GET-able translation endpoint,
+/// so it rides along as a chat-completions JSON body; DeepL and Google both
+/// take it as a query parameter, but under different names.
+enum TextPlacement {
+    Query(&'static str),
+    ChatCompletionBody,
+}
+
+struct ProviderConfig {
+    url: String,
+    method: &'static str,
+    auth_fn: fn(String) -> Auth,
+    text_placement: TextPlacement,
+}
+
+/// Per-provider endpoint, HTTP method, auth scheme and where the source
+/// text goes. Mirrors the base URLs the old `openai_proxy`/`deepl_proxy`/
+/// `google_proxy` commands hardcoded before chunk0-2 consolidated them.
+fn provider_endpoint(provider: &str, source_lang: &str, target_lang: &str) -> Result<ProviderConfig, String> {
+    match provider {
+        "openai" => Ok(ProviderConfig {
+            // OpenAI has no JSON text-translation endpoint to GET; `/v1/audio/translations`
+            // is Whisper audio-to-English only. Drive a translation through chat completions
+            // instead, same as any other instruction-following prompt.
+            url: "https://api.openai.com/v1/chat/completions".to_string(),
+            method: "POST",
+            auth_fn: |token| Auth::Bearer { token },
+            text_placement: TextPlacement::ChatCompletionBody,
+        }),
+        "deepl" => Ok(ProviderConfig {
+            url: format!(
+                "https://api-free.deepl.com/v2/translate?source_lang={}&target_lang={}",
+                source_lang, target_lang
+            ),
+            method: "GET",
+            auth_fn: |key| Auth::DeeplAuthKey { key },
+            text_placement: TextPlacement::Query("text"),
+        }),
+        "google" => Ok(ProviderConfig {
+            url: format!(
+                "https://translation.googleapis.com/language/translate/v2?source={}&target={}",
+                source_lang, target_lang
+            ),
+            method: "GET",
+            auth_fn: |key| Auth::QueryKey {
+                name: "key".to_string(),
+                value: key,
+            },
+            text_placement: TextPlacement::Query("q"),
+        }),
+        other => Err(format!("Unknown translation provider: {}", other)),
+    }
+}
+
+/// `{SCHEME}://translate/<provider>/<source>-<target>/<text>`
+struct TranslateRequest {
+    provider: String,
+    source_lang: String,
+    target_lang: String,
+    text: String,
+}
+
+fn parse_translate_request(uri: &str) -> Result<TranslateRequest, String> {
+    let rest = uri
+        .strip_prefix(&format!("{}://translate/", SCHEME))
+        .ok_or_else(|| format!("Unsupported {}:// path: {}", SCHEME, uri))?;
+
+    let mut segments = rest.splitn(3, '/');
+    let provider = segments.next().ok_or("Missing provider segment")?.to_string();
+    let lang_pair = segments.next().ok_or("Missing language-pair segment")?;
+    let text = segments.next().ok_or("Missing text segment")?;
+
+    let (source_lang, target_lang) = lang_pair
+        .split_once('-')
+        .ok_or("Expected <source>-<target> language pair")?;
+
+    let text = urlencoding::decode(text)
+        .map_err(|e| e.to_string())?
+        .into_owned();
+
+    Ok(TranslateRequest {
+        provider,
+        source_lang: source_lang.to_string(),
+        target_lang: target_lang.to_string(),
+        text,
+    })
+}
+
+/// Registers the async protocol handler on the given Tauri builder. The
+/// translation cache and vault are looked up from managed state on each
+/// request, since the cache is only managed once `.setup()` has run.
+pub fn register<R: tauri::Runtime>(builder: tauri::Builder<R>) -> tauri::Builder<R> {
+    builder.register_asynchronous_uri_scheme_protocol(SCHEME, move |ctx, request, responder| {
+        let app = ctx.app_handle().clone();
+        tauri::async_runtime::spawn(async move {
+            let cache = app.state::<TranslationCache>();
+            let vault = app.state::<VaultState>();
+            respond(&cache, &vault, request, responder).await;
+        });
+    })
+}
+
+async fn respond(
+    cache: &TranslationCache,
+    vault: &VaultState,
+    request: Request<Vec<u8>>,
+    responder: UriSchemeResponder,
+) {
+    let uri = request.uri().to_string();
+
+    let parsed = match parse_translate_request(&uri) {
+        Ok(p) => p,
+        Err(e) => return responder.respond(error_response(400, &e)),
+    };
+
+    if let Ok(Some(cached)) = cache.get(&parsed.provider, &parsed.source_lang, &parsed.target_lang, &parsed.text) {
+        return responder.respond(json_response(200, cached));
+    }
+
+    let options = match translate_request_options(&parsed, vault) {
+        Ok(options) => options,
+        Err(e) => return responder.respond(error_response(401, &e)),
+    };
+
+    match http_request(options).await {
+        Ok(resp) => {
+            let _ = cache.put(
+                &parsed.provider,
+                &parsed.source_lang,
+                &parsed.target_lang,
+                &parsed.text,
+                &resp.body,
+            );
+            responder.respond(json_response(resp.status, resp.body));
+        }
+        Err(e) => responder.respond(error_response(502, &e)),
+    }
+}
+
+/// Resolves the provider's base URL plus its stored API key from the
+/// vault, so a cache miss actually reaches a real provider instead of a
+/// placeholder host.
+fn translate_request_options(req: &TranslateRequest, vault: &VaultState) -> Result<HttpRequestOptions, String> {
+    let config = provider_endpoint(&req.provider, &req.source_lang, &req.target_lang)?;
+
+    let api_key = vault
+        .get(&format!("{}:api_key", req.provider))?
+        .ok_or_else(|| format!("No stored API key for provider {:?}", req.provider))?;
+
+    let (url, body) = match config.text_placement {
+        TextPlacement::Query(param) => {
+            let sep = if config.url.contains('?') { "&" } else { "?" };
+            (
+                format!("{}{}{}={}", config.url, sep, param, urlencoding::encode(&req.text)),
+                None,
+            )
+        }
+        TextPlacement::ChatCompletionBody => {
+            let body = serde_json::json!({
+                "model": "gpt-4o-mini",
+                "messages": [
+                    {
+                        "role": "system",
+                        "content": format!(
+                            "Translate the user's text from {} to {}. Reply with only the translation.",
+                            req.source_lang, req.target_lang
+                        )
+                    },
+                    { "role": "user", "content": req.text }
+                ]
+            })
+            .to_string();
+            (config.url.clone(), Some(body))
+        }
+    };
+
+    Ok(HttpRequestOptions {
+        method: config.method.to_string(),
+        url,
+        headers: Default::default(),
+        body,
+        response_type: ResponseType::Json,
+        auth: (config.auth_fn)(api_key),
+        follow_redirects: true,
+        max_redirections: 10,
+        connect_timeout_ms: None,
+        read_timeout_ms: None,
+        timeout_ms: None,
+    })
+}
+
+fn json_response(status: u16, body: String) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(body.into_bytes())
+        .unwrap()
+}
+
+fn error_response(status: u16, message: &str) -> Response<Vec<u8>> {
+    json_response(status, format!("{{\"error\":{:?}}}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_provider_lang_pair_and_text() {
+        let req = parse_translate_request("readnlearn-cache://translate/deepl/en-fr/hello%20world").unwrap();
+        assert_eq!(req.provider, "deepl");
+        assert_eq!(req.source_lang, "en");
+        assert_eq!(req.target_lang, "fr");
+        assert_eq!(req.text, "hello world");
+    }
+
+    #[test]
+    fn rejects_wrong_scheme() {
+        assert!(parse_translate_request("readnlearn://translate/deepl/en-fr/hi").is_err());
+    }
+
+    #[test]
+    fn rejects_missing_text_segment() {
+        assert!(parse_translate_request("readnlearn-cache://translate/deepl/en-fr").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_lang_pair() {
+        assert!(parse_translate_request("readnlearn-cache://translate/deepl/english/hi").is_err());
+    }
+}
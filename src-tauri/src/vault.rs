@@ -0,0 +1,256 @@
+//! Encrypted credential vault.
+//!
+//! Replaces the old in-memory `HashMap` of API keys with an on-disk store
+//! encrypted under a key derived from a user-chosen master password. The
+//! derived key is only ever held in memory while the vault is unlocked;
+//! nothing sensitive is written to disk in plaintext.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+const VAULT_FILE_NAME: &str = "vault.json";
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VaultEntry {
+    /// Base64-encoded 96-bit nonce used for this entry's ciphertext.
+    nonce: String,
+    /// Base64-encoded ChaCha20-Poly1305 ciphertext.
+    ciphertext: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VaultFile {
+    /// Base64-encoded 16-byte Argon2id salt, shared by every entry.
+    salt: String,
+    entries: HashMap<String, VaultEntry>,
+}
+
+/// Holds the derived key while the vault is unlocked, plus the encrypted
+/// entries so repeated `get_credential` calls don't touch disk; each value
+/// is decrypted on demand in `get`.
+#[derive(Default)]
+pub struct VaultState {
+    inner: Mutex<Option<UnlockedVault>>,
+}
+
+struct UnlockedVault {
+    path: PathBuf,
+    key: [u8; KEY_LEN],
+    entries: HashMap<String, VaultEntry>,
+}
+
+impl VaultState {
+    pub fn unlock(&self, vault_path: &Path, password: &str) -> Result<(), String> {
+        let file = load_or_init(vault_path)?;
+        let salt = base64_decode(&file.salt)?;
+        let key = derive_key(password, &salt)?;
+
+        // Verify the password by attempting to decrypt every existing entry.
+        for entry in file.entries.values() {
+            decrypt_entry(&key, entry)?;
+        }
+
+        let mut guard = self.inner.lock().map_err(|e| e.to_string())?;
+        *guard = Some(UnlockedVault {
+            path: vault_path.to_path_buf(),
+            key,
+            entries: file.entries,
+        });
+        Ok(())
+    }
+
+    pub fn lock(&self) -> Result<(), String> {
+        let mut guard = self.inner.lock().map_err(|e| e.to_string())?;
+        *guard = None;
+        Ok(())
+    }
+
+    pub fn is_unlocked(&self) -> bool {
+        self.inner.lock().map(|g| g.is_some()).unwrap_or(false)
+    }
+
+    pub fn store(&self, full_key: String, value: &str) -> Result<(), String> {
+        let mut guard = self.inner.lock().map_err(|e| e.to_string())?;
+        let vault = guard.as_mut().ok_or("Vault is locked")?;
+
+        let entry = encrypt_entry(&vault.key, value)?;
+        vault.entries.insert(full_key, entry);
+        persist(vault)
+    }
+
+    pub fn get(&self, full_key: &str) -> Result<Option<String>, String> {
+        let guard = self.inner.lock().map_err(|e| e.to_string())?;
+        let vault = guard.as_ref().ok_or("Vault is locked")?;
+
+        match vault.entries.get(full_key) {
+            Some(entry) => Ok(Some(decrypt_entry(&vault.key, entry)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn delete(&self, full_key: &str) -> Result<(), String> {
+        let mut guard = self.inner.lock().map_err(|e| e.to_string())?;
+        let vault = guard.as_mut().ok_or("Vault is locked")?;
+
+        vault.entries.remove(full_key);
+        persist(vault)
+    }
+}
+
+fn load_or_init(vault_path: &Path) -> Result<VaultFile, String> {
+    if !vault_path.exists() {
+        let mut salt_bytes = [0u8; 16];
+        OsRng.fill_bytes(&mut salt_bytes);
+        let file = VaultFile {
+            salt: base64_encode(&salt_bytes),
+            entries: HashMap::new(),
+        };
+        write_vault_file(vault_path, &file)?;
+        return Ok(file);
+    }
+
+    let raw = fs::read_to_string(vault_path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&raw).map_err(|e| e.to_string())
+}
+
+fn write_vault_file(vault_path: &Path, file: &VaultFile) -> Result<(), String> {
+    if let Some(parent) = vault_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let raw = serde_json::to_string_pretty(file).map_err(|e| e.to_string())?;
+    fs::write(vault_path, raw).map_err(|e| e.to_string())
+}
+
+fn persist(vault: &UnlockedVault) -> Result<(), String> {
+    let raw = fs::read_to_string(&vault.path).map_err(|e| e.to_string())?;
+    let mut file: VaultFile = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
+    file.entries = vault.entries.clone();
+    write_vault_file(&vault.path, &file)
+}
+
+fn derive_key(password: &str, salt: &[u8]) -> Result<[u8; KEY_LEN], String> {
+    let mut key = [0u8; KEY_LEN];
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+fn encrypt_entry(key: &[u8; KEY_LEN], value: &str) -> Result<VaultEntry, String> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, value.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    Ok(VaultEntry {
+        nonce: base64_encode(&nonce_bytes),
+        ciphertext: base64_encode(&ciphertext),
+    })
+}
+
+fn decrypt_entry(key: &[u8; KEY_LEN], entry: &VaultEntry) -> Result<String, String> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce_bytes = base64_decode(&entry.nonce)?;
+    let nonce = Nonce::from_slice(&nonce_bytes);
+    let ciphertext = base64_decode(&entry.ciphertext)?;
+
+    let plaintext = cipher
+        .decrypt(nonce, ciphertext.as_slice())
+        .map_err(|_| "Failed to decrypt credential (wrong master password?)".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+fn base64_decode(s: &str) -> Result<Vec<u8>, String> {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD
+        .decode(s)
+        .map_err(|e| e.to_string())
+}
+
+fn vault_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))?;
+    Ok(dir.join("vault.json"))
+}
+
+#[tauri::command]
+pub fn unlock_vault(app: tauri::AppHandle, state: tauri::State<VaultState>, password: String) -> Result<(), String> {
+    let path = vault_path(&app)?;
+    state.unlock(&path, &password)
+}
+
+#[tauri::command]
+pub fn lock_vault(state: tauri::State<VaultState>) -> Result<(), String> {
+    state.lock()
+}
+
+#[tauri::command]
+pub fn store_credential(state: tauri::State<VaultState>, service: String, key: String, value: String) -> Result<(), String> {
+    let full_key = format!("{}:{}", service, key);
+    state.store(full_key, &value)
+}
+
+#[tauri::command]
+pub fn get_credential(state: tauri::State<VaultState>, service: String, key: String) -> Result<Option<String>, String> {
+    if !state.is_unlocked() {
+        return Err("Vault is locked".into());
+    }
+    let full_key = format!("{}:{}", service, key);
+    state.get(&full_key)
+}
+
+#[tauri::command]
+pub fn delete_credential(state: tauri::State<VaultState>, service: String, key: String) -> Result<(), String> {
+    let full_key = format!("{}:{}", service, key);
+    state.delete(&full_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_round_trips() {
+        let key = derive_key("hunter2", b"0123456789abcdef").unwrap();
+        let entry = encrypt_entry(&key, "sk-some-api-key").unwrap();
+        assert_eq!(decrypt_entry(&key, &entry).unwrap(), "sk-some-api-key");
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_password() {
+        let right_key = derive_key("hunter2", b"0123456789abcdef").unwrap();
+        let wrong_key = derive_key("not-it", b"0123456789abcdef").unwrap();
+        let entry = encrypt_entry(&right_key, "sk-some-api-key").unwrap();
+        assert!(decrypt_entry(&wrong_key, &entry).is_err());
+    }
+
+    #[test]
+    fn different_salts_derive_different_keys() {
+        let key_a = derive_key("hunter2", b"0123456789abcdef").unwrap();
+        let key_b = derive_key("hunter2", b"fedcba9876543210").unwrap();
+        assert_ne!(key_a, key_b);
+    }
+}